@@ -0,0 +1,69 @@
+use super::{
+    Element,
+    Encode,
+    Number,
+};
+
+/// A single-precision complex number, packed as two `f32`s (`re`, `im`).
+///
+/// In WGSL a value of this element type is a `vec2<f32>` whose `.x`/`.y`
+/// components are the real and imaginary parts, so the elementwise map bodies
+/// can do complex arithmetic directly on `value_operand`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex32 {
+    pub re: f32,
+    pub im: f32,
+}
+
+impl Complex32 {
+    pub const fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+}
+
+/// A double-precision complex number, packed as two `f32`s in the shader.
+///
+/// WGSL has no native `f64`, so the GPU representation falls back to a
+/// `vec2<f32>` — the extra host precision is only retained until upload.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex64 {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex64 {
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+}
+
+impl Element for Complex32 {
+    const WGSL_TYPE: &'static str = "vec2<f32>";
+}
+
+impl Element for Complex64 {
+    const WGSL_TYPE: &'static str = "vec2<f32>";
+}
+
+impl Number for Complex32 {}
+impl Number for Complex64 {}
+
+impl Encode for Complex32 {
+    const NUM_PACKED: usize = 1;
+
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.re.to_le_bytes());
+        buffer.extend_from_slice(&self.im.to_le_bytes());
+    }
+}
+
+impl Encode for Complex64 {
+    const NUM_PACKED: usize = 1;
+
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&(self.re as f32).to_le_bytes());
+        buffer.extend_from_slice(&(self.im as f32).to_le_bytes());
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use self::pipeline_cache::{
+    CompiledPipeline,
+    PipelineCache,
+};
+use crate::{
+    error::KernelError,
+    kernel::{
+        binding::KernelBindingBuilder,
+        map::{
+            render_map_source,
+            MapSignature,
+        },
+        Kernel,
+        KernelSignature,
+    },
+};
+
+pub mod pipeline_cache;
+
+/// Handle to the GPU device, queue, and the per-device [`PipelineCache`].
+///
+/// Cheap to [`Clone`] — every clone shares the same underlying device and
+/// compiled-pipeline cache.
+#[derive(Clone, Debug)]
+pub struct Gpu(Arc<GpuInner>);
+
+#[derive(Debug)]
+struct GpuInner {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline_cache: PipelineCache,
+}
+
+impl Gpu {
+    /// Dispatch a statically-known kernel.
+    ///
+    /// The WGSL is rendered from the kernel's [`Kernel::source`] on each call and
+    /// looked up in the [`PipelineCache`]; repeated dispatches of the same op on
+    /// tensors of the same element type and rank reuse the compiled pipeline and
+    /// skip shader creation entirely.
+    pub async fn run_kernel<'a, const D: usize, K: Kernel>(
+        &self,
+        args: <K::Signature as KernelSignature>::Args<'a, D>,
+    ) -> Result<(), KernelError> {
+        self.dispatch::<D, K::Signature>(K::LABEL, &K::source(), args)
+            .await
+    }
+
+    /// Dispatch a map whose WGSL body is only known at runtime — used by
+    /// [`FusedMap`](crate::kernel::map::unary::FusedMap) to run a body it
+    /// concatenated from several stages.
+    ///
+    /// The full shader is rendered from `S`'s declaration plus the supplied
+    /// `body`/`index_step`/`map_encoded`, then runs through the same
+    /// cache-backed dispatch path as [`run_kernel`](Gpu::run_kernel).
+    pub async fn run_kernel_source<'a, const D: usize, S: KernelSignature + MapSignature>(
+        &self,
+        label: &str,
+        body: &str,
+        index_step: usize,
+        map_encoded: bool,
+        args: S::Args<'a, D>,
+    ) -> Result<(), KernelError> {
+        let source = render_map_source::<S>(label, body, index_step, map_encoded);
+        self.dispatch::<D, S>(label, &source, args).await
+    }
+
+    /// Fetch the pipeline for the already-rendered WGSL `source`, compiling on a
+    /// cache miss, and dispatch `args` against it.
+    async fn dispatch<'a, const D: usize, S: KernelSignature>(
+        &self,
+        label: &str,
+        source: &str,
+        args: S::Args<'a, D>,
+    ) -> Result<(), KernelError> {
+        let partition = S::task_partition(&args);
+        let pipeline = self.compiled_pipeline(label, source);
+
+        let mut builder = KernelBindingBuilder::new(&self.0.device, &pipeline.compute_pipeline);
+        S::build_bind_group(args, &mut builder)?;
+        let bind_group = builder.build()?;
+
+        let mut encoder = self
+            .0
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some(label) });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some(label),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&pipeline.compute_pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            let [x, y, z] = partition.workgroup_count();
+            pass.dispatch_workgroups(x, y, z);
+        }
+        self.0.queue.submit(Some(encoder.finish()));
+
+        Ok(())
+    }
+
+    /// Fetch the pipeline compiled from `source`, compiling and caching it on the
+    /// first miss.
+    fn compiled_pipeline(&self, label: &str, source: &str) -> Arc<CompiledPipeline> {
+        self.0.pipeline_cache.get_or_compile(source, |source| {
+            let shader_module =
+                self.0
+                    .device
+                    .create_shader_module(wgpu::ShaderModuleDescriptor {
+                        label: Some(label),
+                        source: wgpu::ShaderSource::Wgsl(source.into()),
+                    });
+            let compute_pipeline =
+                self.0
+                    .device
+                    .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                        label: Some(label),
+                        layout: None,
+                        module: &shader_module,
+                        entry_point: Some("main"),
+                        compilation_options: wgpu::PipelineCompilationOptions::default(),
+                        cache: None,
+                    });
+            CompiledPipeline {
+                shader_module,
+                compute_pipeline,
+            }
+        })
+    }
+}
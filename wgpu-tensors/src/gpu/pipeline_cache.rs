@@ -0,0 +1,81 @@
+use std::{
+    collections::{
+        hash_map::DefaultHasher,
+        HashMap,
+    },
+    hash::{
+        Hash,
+        Hasher,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
+};
+
+/// A compiled shader module together with the compute pipeline built from it.
+#[derive(Debug)]
+pub struct CompiledPipeline {
+    pub shader_module: wgpu::ShaderModule,
+    pub compute_pipeline: wgpu::ComputePipeline,
+}
+
+/// Memoizes [`CompiledPipeline`]s keyed by the fully-rendered WGSL source.
+///
+/// Every `run_kernel` dispatch renders a deterministic shader from the
+/// `Map::BODY` and `KernelSignature::DECLARATION` of the kernel it runs, so the
+/// same op on tensors of the same element type and rank produces the same
+/// source every time. Hashing that source and caching the resulting
+/// `wgpu::ShaderModule`/`ComputePipeline` lets repeated dispatches — the common
+/// case in a training loop — skip shader creation entirely.
+///
+/// The map is behind a [`Mutex`] and hands out [`Arc`]s so a compiled pipeline
+/// can outlive the lock guard and be shared across concurrent dispatches.
+#[derive(Debug, Default)]
+pub struct PipelineCache {
+    entries: Mutex<HashMap<u64, Arc<CompiledPipeline>>>,
+}
+
+impl PipelineCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn key(source: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        source.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// A poisoned lock here only means a *previous* caller panicked between two
+    /// `HashMap` operations; the map itself is still a valid cache, so recover
+    /// the guard rather than propagating the panic to every future dispatch.
+    fn lock(&self) -> MutexGuard<'_, HashMap<u64, Arc<CompiledPipeline>>> {
+        self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Return the pipeline compiled from `source`, compiling and inserting it on
+    /// the first miss.
+    ///
+    /// Compilation runs *outside* the lock, so a slow first-miss compile never
+    /// blocks concurrent dispatches of already-cached (or differently-keyed)
+    /// shaders — the guard is only held for the two short map probes. A race on
+    /// the same key may compile twice; the first writer wins and the loser's
+    /// pipeline is dropped. `compile` is not invoked on a cache hit.
+    pub fn get_or_compile(
+        &self,
+        source: &str,
+        compile: impl FnOnce(&str) -> CompiledPipeline,
+    ) -> Arc<CompiledPipeline> {
+        let key = Self::key(source);
+
+        if let Some(pipeline) = self.lock().get(&key) {
+            return pipeline.clone();
+        }
+
+        let pipeline = Arc::new(compile(source));
+
+        self.lock().entry(key).or_insert(pipeline).clone()
+    }
+}
@@ -3,6 +3,10 @@ use std::marker::PhantomData;
 use super::{MapKernel, MapSignature};
 use crate::{
     element::{
+        complex::{
+            Complex32,
+            Complex64,
+        },
         Element,
         Number, Encode,
     },
@@ -97,6 +101,496 @@ impl<const D: usize, T: Element> Tensor<D, T> {
     }
 }
 
+/// A single elementwise stage captured for fusion.
+///
+/// Mirrors the pieces of a [`Map`] that matter when several shape-preserving
+/// maps are concatenated into one kernel body.
+struct FusedStage {
+    label: &'static str,
+    body: &'static str,
+    index_step: usize,
+    map_encoded: bool,
+}
+
+/// A lazily-built chain of shape-preserving elementwise [`Map`]s over a single
+/// operand.
+///
+/// Instead of allocating a fresh [`Tensor`] and dispatching a [`MapKernel`] per
+/// op, the stages are accumulated and, when [`run`](FusedMap::run) is awaited,
+/// emitted as a single WGSL body. Each stage's snippet is wrapped in its own
+/// block and threaded through a `value_chain` local, so the stages compose
+/// without intermediate buffers and without their internal temporaries
+/// colliding.
+///
+/// All fused stages must share the same packing mode (`MAP_ENCODED`) and index
+/// step; when an op disagrees with its neighbours fusion is not possible and
+/// the stages fall back to the per-op path.
+pub struct FusedMap<'a, const D: usize, T: Element> {
+    operand: &'a Tensor<D, T>,
+    stages: Vec<FusedStage>,
+}
+
+impl<'a, const D: usize, T: Element> FusedMap<'a, D, T> {
+    fn new(operand: &'a Tensor<D, T>) -> Self {
+        Self {
+            operand,
+            stages: Vec::new(),
+        }
+    }
+
+    /// Append an elementwise [`Map`] to the chain.
+    pub fn push<M: Map<Signature = UnarySignature<T, T>>>(mut self) -> Self {
+        self.stages.push(FusedStage {
+            label: M::LABEL,
+            body: M::BODY,
+            index_step: M::INDEX_STEP,
+            map_encoded: M::MAP_ENCODED,
+        });
+        self
+    }
+
+    /// Whether every stage shares the same packing mode and index step, so a
+    /// single kernel can cover the whole chain.
+    fn is_fusable(&self) -> bool {
+        let mut stages = self.stages.iter();
+        let Some(first) = stages.next() else {
+            return true;
+        };
+        stages.all(|stage| {
+            stage.map_encoded == first.map_encoded && stage.index_step == first.index_step
+        })
+    }
+
+    /// Concatenate the stage snippets into one WGSL body, chaining each stage's
+    /// output into the next stage's input.
+    ///
+    /// Each snippet runs in its own block so that its `value_operand`/
+    /// `value_result` locals — and any internal temporaries it declares (e.g.
+    /// a complex stage's `magnitude`) — are scoped to that stage and cannot
+    /// clash when the same op appears twice in the chain. The value is threaded
+    /// between blocks through the mutable `value_chain`.
+    fn fused_body(&self) -> String {
+        let mut body = String::from("var value_chain = value_operand;\n");
+        for stage in &self.stages {
+            body.push_str("{\n    let value_operand = value_chain;\n    ");
+            body.push_str(stage.body);
+            body.push_str("\n    value_chain = value_result;\n}\n");
+        }
+        body.push_str("let value_result = value_chain;");
+        body
+    }
+
+    /// Execute the chain.
+    ///
+    /// When every stage shares a packing mode and index step the whole chain is lowered to a
+    /// single dispatch over one generated body; otherwise the stages bail out
+    /// to the per-op path, allocating an intermediate per stage.
+    pub async fn run(self) -> Result<Tensor<D, T>, KernelError> {
+        if self.is_fusable() {
+            let mut result = Tensor::allocate(&self.operand.gpu, self.operand.shape());
+            let index_step = self
+                .stages
+                .first()
+                .map_or(1, |stage| stage.index_step);
+            let map_encoded = self
+                .stages
+                .first()
+                .is_some_and(|stage| stage.map_encoded);
+            self.operand
+                .gpu
+                .run_kernel_source::<D, UnarySignature<T, T>>(
+                    "FusedMap",
+                    &self.fused_body(),
+                    index_step,
+                    map_encoded,
+                    UnaryArgs {
+                        result: &mut result,
+                        operand: self.operand,
+                    },
+                )
+                .await?;
+            Ok(result)
+        } else {
+            let mut result = Tensor::allocate(&self.operand.gpu, self.operand.shape());
+            self.operand
+                .gpu
+                .run_kernel_source::<D, UnarySignature<T, T>>(
+                    self.stages[0].label,
+                    self.stages[0].body,
+                    self.stages[0].index_step,
+                    self.stages[0].map_encoded,
+                    UnaryArgs {
+                        result: &mut result,
+                        operand: self.operand,
+                    },
+                )
+                .await?;
+            for stage in &self.stages[1..] {
+                let mut next = Tensor::allocate(&result.gpu, result.shape());
+                result
+                    .gpu
+                    .run_kernel_source::<D, UnarySignature<T, T>>(
+                        stage.label,
+                        stage.body,
+                        stage.index_step,
+                        stage.map_encoded,
+                        UnaryArgs {
+                            result: &mut next,
+                            operand: &result,
+                        },
+                    )
+                    .await?;
+                result = next;
+            }
+            Ok(result)
+        }
+    }
+}
+
+impl<const D: usize, T: Element> Tensor<D, T> {
+    /// Begin a fused chain of elementwise maps over this tensor.
+    pub fn fuse(&self) -> FusedMap<'_, D, T> {
+        FusedMap::new(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct UnaryScalarArgs<'a, const D: usize, R: Element, A: Element, S: Element> {
+    pub result: &'a mut Tensor<D, R>,
+    pub operand: &'a Tensor<D, A>,
+    pub scalar_0: S,
+}
+
+/// Like [`UnarySignature`] but threads a single runtime scalar of element type
+/// `S` into the WGSL body as the named constant `scalar_0`.
+pub struct UnaryScalarSignature<R: Element, A: Element, S: Element>(PhantomData<(R, A, S)>);
+
+impl<R: Element, A: Element, S: Element> KernelSignature for UnaryScalarSignature<R, A, S> {
+    const DECLARATION: KernelDeclaration = KernelDeclaration {
+        bindings: &[
+            KernelBindingDeclaration::read_write::<R>("result"),
+            KernelBindingDeclaration::read_only::<A>("operand"),
+        ],
+        parameters: &[
+            KernelParameterDeclaration::shaped("op_strides"),
+            KernelParameterDeclaration::shaped("op_shape"),
+            KernelParameterDeclaration::int("result_offset"),
+            KernelParameterDeclaration::shaped("result_strides"),
+            KernelParameterDeclaration::int("operand_offset"),
+            KernelParameterDeclaration::shaped("operand_strides"),
+            KernelParameterDeclaration::scalar::<S>("scalar_0"),
+        ],
+    };
+
+    type Args<'a, const D: usize> = UnaryScalarArgs<'a, D, R, A, S>;
+
+    fn build_bind_group<'gpu, 'tensor, const D: usize>(
+        args: Self::Args<'tensor, D>,
+        builder: &mut KernelBindingBuilder<'gpu, 'tensor, D>,
+    ) -> Result<(), KernelError> {
+        builder.add_binding("result", args.result)?;
+        builder.add_binding("operand", args.operand)?;
+
+        let result_strider = args.result.strider();
+        let op_shape = result_strider.shape();
+        builder.add_parameter("op_strides", contiguous_strides(&op_shape))?;
+        builder.add_parameter("op_shape", op_shape)?;
+
+        builder.add_parameter("result_offset", result_strider.offset())?;
+        builder.add_parameter("result_strides", result_strider.strides())?;
+
+        let operand_strider = args.operand.strider();
+        builder.add_parameter("operand_offset", operand_strider.offset())?;
+        builder.add_parameter("operand_strides", operand_strider.strides())?;
+
+        builder.add_parameter("scalar_0", args.scalar_0)?;
+
+        Ok(())
+    }
+
+    fn task_partition<'a, const D: usize>(args: &Self::Args<'a, D>) -> TaskPartition {
+        TaskPartition::for_result(&args.result)
+    }
+}
+
+impl<R: Element, A: Element, S: Element> MapSignature for UnaryScalarSignature<R, A, S> {
+    const INPUTS: &'static [&'static str] = &["operand"];
+    const OUTPUTS: &'static [&'static str] = &["result"];
+}
+
+#[derive(Debug)]
+pub struct UnaryScalar2Args<'a, const D: usize, R: Element, A: Element, S: Element> {
+    pub result: &'a mut Tensor<D, R>,
+    pub operand: &'a Tensor<D, A>,
+    pub scalar_0: S,
+    pub scalar_1: S,
+}
+
+/// Like [`UnaryScalarSignature`] but threads two runtime scalars, exposed as
+/// `scalar_0` and `scalar_1` — used by ops such as `clamp(min, max)`.
+pub struct UnaryScalar2Signature<R: Element, A: Element, S: Element>(PhantomData<(R, A, S)>);
+
+impl<R: Element, A: Element, S: Element> KernelSignature for UnaryScalar2Signature<R, A, S> {
+    const DECLARATION: KernelDeclaration = KernelDeclaration {
+        bindings: &[
+            KernelBindingDeclaration::read_write::<R>("result"),
+            KernelBindingDeclaration::read_only::<A>("operand"),
+        ],
+        parameters: &[
+            KernelParameterDeclaration::shaped("op_strides"),
+            KernelParameterDeclaration::shaped("op_shape"),
+            KernelParameterDeclaration::int("result_offset"),
+            KernelParameterDeclaration::shaped("result_strides"),
+            KernelParameterDeclaration::int("operand_offset"),
+            KernelParameterDeclaration::shaped("operand_strides"),
+            KernelParameterDeclaration::scalar::<S>("scalar_0"),
+            KernelParameterDeclaration::scalar::<S>("scalar_1"),
+        ],
+    };
+
+    type Args<'a, const D: usize> = UnaryScalar2Args<'a, D, R, A, S>;
+
+    fn build_bind_group<'gpu, 'tensor, const D: usize>(
+        args: Self::Args<'tensor, D>,
+        builder: &mut KernelBindingBuilder<'gpu, 'tensor, D>,
+    ) -> Result<(), KernelError> {
+        builder.add_binding("result", args.result)?;
+        builder.add_binding("operand", args.operand)?;
+
+        let result_strider = args.result.strider();
+        let op_shape = result_strider.shape();
+        builder.add_parameter("op_strides", contiguous_strides(&op_shape))?;
+        builder.add_parameter("op_shape", op_shape)?;
+
+        builder.add_parameter("result_offset", result_strider.offset())?;
+        builder.add_parameter("result_strides", result_strider.strides())?;
+
+        let operand_strider = args.operand.strider();
+        builder.add_parameter("operand_offset", operand_strider.offset())?;
+        builder.add_parameter("operand_strides", operand_strider.strides())?;
+
+        builder.add_parameter("scalar_0", args.scalar_0)?;
+        builder.add_parameter("scalar_1", args.scalar_1)?;
+
+        Ok(())
+    }
+
+    fn task_partition<'a, const D: usize>(args: &Self::Args<'a, D>) -> TaskPartition {
+        TaskPartition::for_result(&args.result)
+    }
+}
+
+impl<R: Element, A: Element, S: Element> MapSignature for UnaryScalar2Signature<R, A, S> {
+    const INPUTS: &'static [&'static str] = &["operand"];
+    const OUTPUTS: &'static [&'static str] = &["result"];
+}
+
+impl<const D: usize, T: Element> Tensor<D, T> {
+    pub async fn map_unary_scalar_elementwise<
+        'a,
+        M: Map<Signature = UnaryScalarSignature<R, T, S>>,
+        R: Element,
+        S: Element,
+    >(
+        &self,
+        scalar_0: S,
+    ) -> Result<Tensor<D, R>, KernelError> {
+        let mut result = Tensor::allocate(&self.gpu, self.shape());
+        self.gpu
+            .run_kernel::<D, MapKernel<M>>(UnaryScalarArgs {
+                result: &mut result,
+                operand: self,
+                scalar_0,
+            })
+            .await?;
+        Ok(result)
+    }
+
+    pub async fn map_unary_scalar2_elementwise<
+        'a,
+        M: Map<Signature = UnaryScalar2Signature<R, T, S>>,
+        R: Element,
+        S: Element,
+    >(
+        &self,
+        scalar_0: S,
+        scalar_1: S,
+    ) -> Result<Tensor<D, R>, KernelError> {
+        let mut result = Tensor::allocate(&self.gpu, self.shape());
+        self.gpu
+            .run_kernel::<D, MapKernel<M>>(UnaryScalar2Args {
+                result: &mut result,
+                operand: self,
+                scalar_0,
+                scalar_1,
+            })
+            .await?;
+        Ok(result)
+    }
+}
+
+pub struct ElementwiseClamp<T>(PhantomData<T>);
+impl<T: Element + Number> Map for ElementwiseClamp<T> {
+    const LABEL: &'static str = "ElementwiseClamp";
+    const BODY: &'static str = "let value_result = clamp(value_operand, scalar_0, scalar_1);";
+    type Signature = UnaryScalar2Signature<T, T, T>;
+}
+
+macro_rules! unary_scalar_func {
+    ($kernel:ident, $body:expr, $tensor_func:ident) => {
+        pub struct $kernel<T>(PhantomData<T>);
+
+        impl<T: Element + Number> Map for $kernel<T> {
+            const LABEL: &'static str = stringify!($kernel);
+            const BODY: &'static str = $body;
+            type Signature = UnaryScalarSignature<T, T, T>;
+        }
+
+        impl<const D: usize, T: Element + Number> Tensor<D, T> {
+            pub async fn $tensor_func(&self, scalar_0: T) -> Result<Tensor<D, T>, KernelError> {
+                self.map_unary_scalar_elementwise::<$kernel<T>, T, T>(scalar_0)
+                    .await
+            }
+        }
+    };
+}
+
+unary_scalar_func!(
+    ElementwiseClampMin,
+    "let value_result = max(value_operand, scalar_0);",
+    clamp_min
+);
+unary_scalar_func!(
+    ElementwiseClampMax,
+    "let value_result = min(value_operand, scalar_0);",
+    clamp_max
+);
+unary_scalar_func!(
+    ElementwisePowScalar,
+    "let value_result = pow(value_operand, scalar_0);",
+    pow_scalar
+);
+unary_scalar_func!(
+    ElementwiseAddScalar,
+    "let value_result = value_operand + scalar_0;",
+    add_scalar
+);
+unary_scalar_func!(
+    ElementwiseMulScalar,
+    "let value_result = value_operand * scalar_0;",
+    mul_scalar
+);
+
+impl<const D: usize, T: Element + Number> Tensor<D, T> {
+    pub async fn clamp(&self, min: T, max: T) -> Result<Tensor<D, T>, KernelError> {
+        self.map_unary_scalar2_elementwise::<ElementwiseClamp<T>, T, T>(min, max)
+            .await
+    }
+}
+
+#[derive(Debug)]
+pub struct UnaryInplaceArgs<'a, const D: usize, T: Element> {
+    pub operand: &'a mut Tensor<D, T>,
+}
+
+/// An in-place unary signature: a single `read_write` binding serves as both
+/// operand and result.
+///
+/// Pure elementwise maps compute each output element from the same-index input
+/// element, so reading a value out of the buffer and writing the result back
+/// into the same slot is always safe. Only one binding is declared — aliasing a
+/// writable storage buffer with a second binding is rejected by wgpu — and no
+/// intermediate [`Tensor`] is allocated. The codegen loads the binding into
+/// `value_operand`; the wrapped body then stores its `value_result` back into
+/// `value_operand`, which is written out to the same buffer.
+pub struct UnaryInplaceSignature<T: Element>(PhantomData<T>);
+
+impl<T: Element> KernelSignature for UnaryInplaceSignature<T> {
+    const DECLARATION: KernelDeclaration = KernelDeclaration {
+        bindings: &[KernelBindingDeclaration::read_write::<T>("operand")],
+        parameters: &[
+            KernelParameterDeclaration::shaped("op_strides"),
+            KernelParameterDeclaration::shaped("op_shape"),
+            KernelParameterDeclaration::int("operand_offset"),
+            KernelParameterDeclaration::shaped("operand_strides"),
+        ],
+    };
+
+    type Args<'a, const D: usize> = UnaryInplaceArgs<'a, D, T>;
+
+    fn build_bind_group<'gpu, 'tensor, const D: usize>(
+        args: Self::Args<'tensor, D>,
+        builder: &mut KernelBindingBuilder<'gpu, 'tensor, D>,
+    ) -> Result<(), KernelError> {
+        let strider = args.operand.strider();
+        let op_shape = strider.shape();
+        let operand_offset = strider.offset();
+        let operand_strides = strider.strides();
+
+        builder.add_binding("operand", args.operand)?;
+
+        builder.add_parameter("op_strides", contiguous_strides(&op_shape))?;
+        builder.add_parameter("op_shape", op_shape)?;
+        builder.add_parameter("operand_offset", operand_offset)?;
+        builder.add_parameter("operand_strides", operand_strides)?;
+
+        Ok(())
+    }
+
+    fn task_partition<'a, const D: usize>(args: &Self::Args<'a, D>) -> TaskPartition {
+        TaskPartition::for_result(&args.operand)
+    }
+}
+
+impl<T: Element> MapSignature for UnaryInplaceSignature<T> {
+    // The lone `operand` binding is both the input and the output.
+    const INPUTS: &'static [&'static str] = &["operand"];
+    const OUTPUTS: &'static [&'static str] = &["operand"];
+}
+
+impl<const D: usize, T: Element> Tensor<D, T> {
+    pub async fn map_unary_elementwise_inplace<M: Map<Signature = UnarySignature<T, T>>>(
+        &mut self,
+    ) -> Result<(), KernelError> {
+        let gpu = self.gpu.clone();
+        // The binding is loaded into `value_operand`; the op's body computes
+        // `value_result`, which we store back into `value_operand` so it is
+        // written out to the same buffer.
+        let body = format!("{}\n    value_operand = value_result;", M::BODY);
+        gpu.run_kernel_source::<D, UnaryInplaceSignature<T>>(
+            M::LABEL,
+            &body,
+            M::INDEX_STEP,
+            M::MAP_ENCODED,
+            UnaryInplaceArgs { operand: self },
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+macro_rules! unary_inplace {
+    ($kernel:ident, $method:ident) => {
+        impl<const D: usize, T: Element + Number> Tensor<D, T> {
+            pub async fn $method(&mut self) -> Result<(), KernelError> {
+                self.map_unary_elementwise_inplace::<$kernel<T>>().await
+            }
+        }
+    };
+}
+
+unary_inplace!(ElementwiseNegate, neg_);
+unary_inplace!(ElementwiseCos, cos_);
+unary_inplace!(ElementwiseSin, sin_);
+unary_inplace!(ElementwiseTan, tan_);
+unary_inplace!(ElementwiseExp, exp_);
+unary_inplace!(ElementwiseLog, log_);
+unary_inplace!(ElementwiseSqrt, sqrt_);
+unary_inplace!(ElementwiseAbsolute, abs_);
+unary_inplace!(ElementwiseCeil, ceil_);
+unary_inplace!(ElementwiseFloor, floor_);
+unary_inplace!(ElementwiseRound, round_);
+
 pub struct Identity<T>(PhantomData<T>);
 impl<T: Element> Map for Identity<T> {
     const LABEL: &'static str = "Identity";
@@ -156,6 +650,18 @@ macro_rules! unary_func_kernel {
     };
 }
 
+macro_rules! unary_func_body_kernel {
+    ($kernel:ident, $body:expr) => {
+        pub struct $kernel<T>(PhantomData<T>);
+
+        impl<T: Element + Number> Map for $kernel<T> {
+            const LABEL: &'static str = stringify!($kernel);
+            const BODY: &'static str = concat!("let value_result = ", $body, ";");
+            type Signature = UnarySignature<T, T>;
+        }
+    };
+}
+
 macro_rules! unary_tensor_impl {
     ($kernel:ident, $tensor_func:ident) => {
         impl<const D: usize, T: Element + Number> Tensor<D, T> {
@@ -174,6 +680,10 @@ macro_rules! unary_func {
     ($kernel:ident, $func:ident) => {
         unary_func!($kernel, $func, $func);
     };
+    ($kernel:ident, $tensor_func:ident = $body:expr) => {
+        unary_func_body_kernel!($kernel, $body);
+        unary_tensor_impl!($kernel, $tensor_func);
+    };
 }
 
 unary_func!(ElementwiseDegrees, degrees);
@@ -205,3 +715,159 @@ unary_func!(ElementwiseCeil, ceil);
 unary_func!(ElementwiseFloor, floor);
 unary_func!(ElementwiseRound, round);
 unary_func!(ElementwiseSaturate, saturate);
+
+// Complex-number elementwise maps.
+//
+// WGSL has no native complex type, so each body operates directly on the
+// `vec2<f32>` packing of [`Complex32`]/[`Complex64`] — `.x` is the real part
+// and `.y` the imaginary part. `conj`/`recip`/`cexp`/`clog` spell out the
+// complex arithmetic inline; `re`/`im` map `Complex -> f32` and so take a
+// differing result element, which `map_unary_elementwise::<M, R>` already
+// supports. Negate/exp/log are named `cneg`/`cexp`/`clog` so they don't collide
+// with the generic `Number` ops of the same name.
+macro_rules! complex_unary {
+    ($kernel:ident, $complex:ty, $result:ty, $method:ident, $body:expr) => {
+        pub enum $kernel {}
+        impl Map for $kernel {
+            const LABEL: &'static str = stringify!($kernel);
+            const BODY: &'static str = $body;
+            type Signature = UnarySignature<$result, $complex>;
+        }
+
+        impl<const D: usize> Tensor<D, $complex> {
+            pub async fn $method(&self) -> Result<Tensor<D, $result>, KernelError> {
+                self.map_unary_elementwise::<$kernel, $result>().await
+            }
+        }
+    };
+}
+
+macro_rules! complex_unary_ops {
+    ($complex:ty, $real:ty, $neg:ident, $conj:ident, $recip:ident, $exp:ident, $log:ident, $re:ident, $im:ident) => {
+        complex_unary!(
+            $neg,
+            $complex,
+            $complex,
+            cneg,
+            "let value_result = -value_operand;"
+        );
+        complex_unary!(
+            $conj,
+            $complex,
+            $complex,
+            conj,
+            "let value_result = vec2<f32>(value_operand.x, -value_operand.y);"
+        );
+        complex_unary!(
+            $recip,
+            $complex,
+            $complex,
+            recip,
+            concat!(
+                "let norm_sq = value_operand.x * value_operand.x + value_operand.y * value_operand.y;\n",
+                "let value_result = vec2<f32>(value_operand.x / norm_sq, -value_operand.y / norm_sq);"
+            )
+        );
+        complex_unary!(
+            $exp,
+            $complex,
+            $complex,
+            cexp,
+            concat!(
+                "let magnitude = exp(value_operand.x);\n",
+                "let value_result = vec2<f32>(magnitude * cos(value_operand.y), magnitude * sin(value_operand.y));"
+            )
+        );
+        complex_unary!(
+            $log,
+            $complex,
+            $complex,
+            clog,
+            concat!(
+                "let modulus = sqrt(value_operand.x * value_operand.x + value_operand.y * value_operand.y);\n",
+                "let value_result = vec2<f32>(log(modulus), atan2(value_operand.y, value_operand.x));"
+            )
+        );
+        complex_unary!($re, $complex, $real, re, "let value_result = value_operand.x;");
+        complex_unary!($im, $complex, $real, im, "let value_result = value_operand.y;");
+    };
+}
+
+complex_unary_ops!(
+    Complex32,
+    f32,
+    Complex32Negate,
+    Complex32Conjugate,
+    Complex32Reciprocal,
+    Complex32Exp,
+    Complex32Log,
+    Complex32Real,
+    Complex32Imag
+);
+// `re`/`im` extract an `f32` even for `Complex64`: the GPU packing is
+// `vec2<f32>`, so the shader only ever has `f32` components to hand back.
+complex_unary_ops!(
+    Complex64,
+    f32,
+    Complex64Negate,
+    Complex64Conjugate,
+    Complex64Reciprocal,
+    Complex64Exp,
+    Complex64Log,
+    Complex64Real,
+    Complex64Imag
+);
+
+// Neural-net activation functions. Each is a pure elementwise map and so fits
+// `UnarySignature<T, T>` directly, spelled out as a hand-written WGSL body over
+// `value_operand`.
+unary_func!(
+    ElementwiseSigmoid,
+    sigmoid = "1.0 / (1.0 + exp(-value_operand))"
+);
+unary_func!(ElementwiseRelu, relu = "max(value_operand, 0.0)");
+unary_func!(
+    ElementwiseGelu,
+    gelu = "0.5 * value_operand * (1.0 + tanh(0.7978845608 * (value_operand + 0.044715 * value_operand * value_operand * value_operand)))"
+);
+unary_func!(
+    ElementwiseSilu,
+    silu = "value_operand / (1.0 + exp(-value_operand))"
+);
+unary_tensor_impl!(ElementwiseSilu, swish);
+unary_func!(
+    ElementwiseSoftplus,
+    softplus = "log(1.0 + exp(value_operand))"
+);
+
+// `leaky_relu` carries its slope as a runtime scalar, so it rides on the
+// scalar-parameterized signature.
+unary_scalar_func!(
+    ElementwiseLeakyRelu,
+    "let value_result = select(scalar_0 * value_operand, value_operand, value_operand > 0.0);",
+    leaky_relu
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::Gpu;
+
+    // Fills a tensor, runs `neg_()` and `abs_()` in place, reads the buffer back,
+    // and asserts the contents actually changed. This exercises the full
+    // dispatch — so it catches both a no-op in-place path and a bind group that
+    // fails wgpu's "writable storage buffer cannot alias" validation.
+    #[test]
+    fn in_place_unary_mutates_the_buffer() {
+        pollster::block_on(async {
+            let gpu = Gpu::new().await.expect("no gpu adapter available");
+
+            let mut tensor = Tensor::from_slice(&gpu, [4], &[1.0f32, -2.0, 3.0, -4.0]);
+            tensor.neg_().await.unwrap();
+            assert_eq!(tensor.to_vec().await.unwrap(), [-1.0, 2.0, -3.0, 4.0]);
+
+            tensor.abs_().await.unwrap();
+            assert_eq!(tensor.to_vec().await.unwrap(), [1.0, 2.0, 3.0, 4.0]);
+        });
+    }
+}